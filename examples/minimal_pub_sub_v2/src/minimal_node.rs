@@ -11,8 +11,8 @@ async fn main() -> Result<(), Error> {
 
     let mut node = rclrs::create_node_with_default_context("minimal_node")?;
 
-    let publisher =
-        node.create_publisher::<std_msgs::msg::String>("topic", rclrs::QOS_PROFILE_DEFAULT)?;
+    let publisher = node
+        .create_publisher::<std_msgs::msg::String>("topic", rclrs::QoSProfile::sensor_data())?;
 
     let mut message = std_msgs::msg::String::default();
 
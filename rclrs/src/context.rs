@@ -1,5 +1,6 @@
 mod builder;
 mod default_context;
+mod signal_handler;
 use std::string::String;
 use std::sync::{Arc, Mutex};
 
@@ -7,6 +8,8 @@ pub use self::builder::*;
 pub use self::default_context::DefaultContext;
 use crate::rcl_bindings::*;
 use crate::error::{to_rclrs_result, RclrsError};
+use crate::parameter::ParameterOverrides;
+use crate::{Executor, WaitSet};
 
 impl Drop for rcl_context_t {
     fn drop(&mut self) {
@@ -44,23 +47,43 @@ unsafe impl Send for rcl_context_t {}
 ///
 pub struct Context {
     pub(crate) rcl_context_mtx: Arc<Mutex<rcl_context_t>>,
-    pub(crate) shutdown_callback: Option<Box<dyn Fn() + Send + Sync>>,
+    pub(crate) shutdown_callback: Option<Arc<dyn Fn() + Send + Sync>>,
+    /// Parameter overrides parsed from the `--ros-args -p`/`--params-file` command line arguments
+    /// this context was created with, handed to nodes at creation time.
+    pub(crate) parameter_overrides: ParameterOverrides,
 }
 
 impl Context {
     /// See [`ContextBuilder::new()`] for documentation.
     #[allow(clippy::new_ret_no_self)]
     pub fn new(args: impl IntoIterator<Item = String>) -> Result<Self, RclrsError> {
-        Self::builder(args).build()
+        let args: Vec<String> = args.into_iter().collect();
+        // Parse parameter overrides out of the same argv before it is handed to rcl, so nodes can
+        // override their declared defaults from `--ros-args -p`/`--params-file`.
+        let parameter_overrides = ParameterOverrides::from_args(&args);
+        let mut context = Self::builder(args).build()?;
+        context.parameter_overrides = parameter_overrides;
+        // Register with the process-global signal handler so that a SIGINT/SIGTERM invalidates this
+        // context along with every other live one. Both this registration and the parameter-override
+        // parsing above are done here rather than in `ContextBuilder::build`, so the lower-level
+        // `Context::builder(args).build()` path intentionally skips them — see [`Context::builder`].
+        signal_handler::register_context(&context.rcl_context_mtx);
+        Ok(context)
+    }
+
+    /// Returns the parameter overrides parsed from this context's command line arguments.
+    ///
+    /// These feed a node's [`NodeOptions`][crate::NodeOptions] so that declared defaults can be
+    /// overridden the standard ROS 2 way.
+    pub fn parameter_overrides(&self) -> &ParameterOverrides {
+        &self.parameter_overrides
     }
 
     /// Checks if the context is still valid.
     ///
-    /// This will return `false` when a signal has caused the context to shut down (currently
-    /// unimplemented).
+    /// This will return `false` once a `SIGINT`/`SIGTERM` has caused the signal handler to call
+    /// `rcl_shutdown()` on the context, so `while context.ok()` loops exit cleanly on Ctrl-C.
     pub fn ok(&self) -> bool {
-        // This will currently always return true, but once we have a signal handler, the signal
-        // handler could call `rcl_shutdown()`, hence making the context invalid.
         let rcl_context = &mut *self.rcl_context_mtx.lock().unwrap();
         // SAFETY: No preconditions for this function.
         unsafe { rcl_context_is_valid(rcl_context) }
@@ -139,8 +162,15 @@ impl Context {
     ///
     /// Convenience function equivalent to [`ContextBuilder::new()`][2].
     ///
+    /// Note that [`build`][3] only initializes the underlying `rcl_context_t`. Unlike
+    /// [`Context::new`], it does *not* register the context with the signal handler or parse
+    /// parameter overrides from the arguments, so a context created straight from the builder is not
+    /// invalidated on Ctrl-C and carries no overrides. Prefer [`Context::new`] unless you need the
+    /// bare context.
+    ///
     /// [1]: crate::ContextBuilder
     /// [2]: crate::ContextBuilder::new
+    /// [3]: crate::ContextBuilder::build
     ///
     /// # Example
     /// ```
@@ -153,10 +183,36 @@ impl Context {
         ContextBuilder::new(args)
     }
 
+    /// Creates a single-threaded async [`Executor`][1] backed by a [`WaitSet`][2] reactor.
+    ///
+    /// Futures spawned on the executor can `.await` a node's subscriptions, timers and service
+    /// callbacks on one thread, replacing the pattern of a blocking [`spin`][3] per node. A
+    /// subscription obtained from
+    /// [`Node::create_subscription_stream`][crate::Node::create_subscription_stream] is driven by
+    /// this reactor once its node is added to the wait set. The optional `throttling` duration
+    /// bounds how long each tick blocks in `rcl_wait`, amortizing the syscall under high message
+    /// rates; when `None`, a small default quantum is used.
+    ///
+    /// [1]: crate::Executor
+    /// [2]: crate::WaitSet
+    /// [3]: crate::spin
+    pub fn create_executor(
+        &self,
+        throttling: Option<std::time::Duration>,
+    ) -> Result<Executor, RclrsError> {
+        let wait_set = WaitSet::new_for_context(self)?;
+        Ok(Executor::new(wait_set, throttling))
+    }
+
     /// Add on shutdown callback
     ///
-    /// To trigger wait set, this callback will be invoked after context shutdown.
+    /// To trigger wait set, this callback will be invoked after context shutdown. The callback is
+    /// also registered with the process-global signal handler so that a `SIGINT`/`SIGTERM` fires it
+    /// and wakes anything blocked in [`WaitSet::wait`][crate::WaitSet::wait], not just an explicit
+    /// [`shutdown`][Self::shutdown].
     pub fn add_on_shutdown_callback(&mut self, callback: Box<dyn Fn() + Send + Sync>) {
+        let callback: Arc<dyn Fn() + Send + Sync> = Arc::from(callback);
+        signal_handler::register_shutdown_callback(Arc::clone(&callback));
         self.shutdown_callback = Some(callback);
     }
 
@@ -0,0 +1,133 @@
+use std::mem;
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+
+use libc::{c_int, c_void, SA_RESTART, SIGINT, SIGTERM};
+use once_cell::sync::OnceCell;
+
+use crate::error::to_rclrs_result;
+use crate::rcl_bindings::*;
+
+/// Process-global registry of every live context's `rcl_context_mtx`.
+///
+/// Entries are stored as [`Weak`] references so that a context which has been dropped does not
+/// keep its `rcl_context_t` alive and is simply skipped (and pruned) when the signal handler walks
+/// the registry.
+static CONTEXT_REGISTRY: OnceCell<Mutex<Vec<Weak<Mutex<rcl_context_t>>>>> = OnceCell::new();
+
+/// Callbacks fired after each context has been shut down, used to wake blocked wait sets.
+static SHUTDOWN_CALLBACKS: OnceCell<Mutex<Vec<Arc<dyn Fn() + Send + Sync>>>> = OnceCell::new();
+
+/// Write end of the self-pipe. The async-signal-safe handler writes a single byte here; the
+/// handler thread blocks reading the read end.
+static WAKE_FD: OnceCell<c_int> = OnceCell::new();
+
+/// The OS signal handler.
+///
+/// This runs in a signal context, so it must stay async-signal-safe: it does nothing but write a
+/// single byte to the self-pipe, waking the dedicated handler thread which performs the real work.
+extern "C" fn handle_signal(_signum: c_int) {
+    if let Some(&fd) = WAKE_FD.get() {
+        let byte: u8 = 1;
+        // SAFETY: `write` is async-signal-safe and `fd` is a valid descriptor for the lifetime of
+        // the process.
+        unsafe {
+            let _ = libc::write(fd, &byte as *const u8 as *const c_void, 1);
+        }
+    }
+}
+
+/// Registers a context with the global registry, installing the OS signal handler the first time
+/// any context is created.
+///
+/// Called from [`Context::new`][crate::Context::new], so that a `SIGINT`/`SIGTERM` invalidates all
+/// live contexts created that way and wakes anything blocked in
+/// [`WaitSet::wait`][crate::WaitSet::wait]. A context built directly through
+/// [`ContextBuilder::build`][crate::ContextBuilder::build] is not registered here.
+pub(crate) fn register_context(rcl_context_mtx: &Arc<Mutex<rcl_context_t>>) {
+    let registry = CONTEXT_REGISTRY.get_or_init(|| {
+        install_signal_handler();
+        Mutex::new(Vec::new())
+    });
+    registry
+        .lock()
+        .unwrap()
+        .push(Arc::downgrade(rcl_context_mtx));
+}
+
+/// Registers a context's shutdown callback so the signal handler fires it after `rcl_shutdown`.
+///
+/// Called from [`Context::add_on_shutdown_callback`][crate::Context::add_on_shutdown_callback] so
+/// that a `SIGINT`/`SIGTERM` wakes anything the callback is responsible for unblocking, e.g. a
+/// [`WaitSet::wait`][crate::WaitSet::wait] the context is driving.
+pub(crate) fn register_shutdown_callback(callback: Arc<dyn Fn() + Send + Sync>) {
+    SHUTDOWN_CALLBACKS
+        .get_or_init(|| Mutex::new(Vec::new()))
+        .lock()
+        .unwrap()
+        .push(callback);
+}
+
+/// Installs the OS signal handler and spawns the dedicated handler thread.
+fn install_signal_handler() {
+    let mut fds: [c_int; 2] = [-1, -1];
+    // SAFETY: `fds` points to a two-element array as required by `pipe`.
+    let ret = unsafe { libc::pipe(fds.as_mut_ptr()) };
+    assert_eq!(ret, 0, "Failed to create self-pipe for signal handler");
+    let (read_fd, write_fd) = (fds[0], fds[1]);
+    let _ = WAKE_FD.set(write_fd);
+
+    // SAFETY: `handle_signal` is async-signal-safe, see its documentation, so it is a valid
+    // disposition. `sigaction` with a zeroed, empty mask has no further preconditions.
+    unsafe {
+        let mut action: libc::sigaction = mem::zeroed();
+        action.sa_sigaction = handle_signal as usize;
+        action.sa_flags = SA_RESTART;
+        libc::sigemptyset(&mut action.sa_mask);
+        for signum in [SIGINT, SIGTERM] {
+            libc::sigaction(signum, &action, std::ptr::null_mut());
+        }
+    }
+
+    thread::spawn(move || loop {
+        let mut byte: u8 = 0;
+        // SAFETY: `read_fd` is a valid descriptor owned by this thread.
+        let n = unsafe { libc::read(read_fd, &mut byte as *mut u8 as *mut c_void, 1) };
+        if n <= 0 {
+            continue;
+        }
+        shutdown_all_contexts();
+    });
+}
+
+/// Walks the registry, shutting down every still-valid context, then fires every registered
+/// shutdown callback once.
+///
+/// Dropped contexts are pruned from the registry as they are encountered. Registered callbacks are
+/// never pruned, so a callback registered by a context that has since been dropped is still fired.
+fn shutdown_all_contexts() {
+    if let Some(registry) = CONTEXT_REGISTRY.get() {
+        let mut registry = registry.lock().unwrap();
+        registry.retain(|weak| {
+            let Some(rcl_context_mtx) = weak.upgrade() else {
+                return false;
+            };
+            let rcl_context = &mut *rcl_context_mtx.lock().unwrap();
+            // SAFETY: No preconditions for this function.
+            if unsafe { rcl_context_is_valid(rcl_context) } {
+                // SAFETY: The context is valid, which is the only precondition.
+                let ret = unsafe { rcl_shutdown(rcl_context) };
+                if let Err(e) = to_rclrs_result(ret) {
+                    panic!("Failed to shut down context from signal handler: {:?}", e);
+                }
+            }
+            true
+        });
+    }
+
+    if let Some(callbacks) = SHUTDOWN_CALLBACKS.get() {
+        for callback in callbacks.lock().unwrap().iter() {
+            callback();
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context as TaskContext, Poll, Wake, Waker};
+use std::time::{Duration, Instant};
+
+use crate::error::RclrsError;
+use crate::WaitSet;
+
+/// Identifier of a task owned by an [`Executor`].
+type TaskId = usize;
+
+type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Shared ready-queue into which wakers push the id of the task that should be polled next tick.
+#[derive(Clone, Default)]
+struct ReadyQueue(Arc<Mutex<VecDeque<TaskId>>>);
+
+impl ReadyQueue {
+    fn push(&self, id: TaskId) {
+        self.0.lock().unwrap().push_back(id);
+    }
+
+    /// Drains every currently-ready task id, returning them as a batch.
+    fn drain(&self) -> Vec<TaskId> {
+        self.0.lock().unwrap().drain(..).collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.lock().unwrap().is_empty()
+    }
+}
+
+/// Waker that simply records its task as ready; the executor does the real polling on its thread.
+struct TaskWaker {
+    id: TaskId,
+    ready_queue: ReadyQueue,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.ready_queue.push(self.id);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.ready_queue.push(self.id);
+    }
+}
+
+/// A single-threaded executor that drives a [`WaitSet`] as its reactor.
+///
+/// A node's subscriptions, timers and service callbacks can be `.await`ed on one thread instead of
+/// bolting `tokio` on top of a blocking [`spin`][crate::spin] with a thread per node.
+///
+/// The loop is modeled on the gst threadshare throttling executor: each tick it computes a throttle
+/// quantum (`max_throttling`), collects every task that became ready, blocks in `rcl_wait` only up
+/// to the remaining quantum, then polls the woken futures in a single batch before looping. This
+/// amortizes the wait syscall and bounds wakeup frequency under high message rates.
+pub struct Executor {
+    max_throttling: Duration,
+    wait_set: WaitSet,
+    tasks: HashMap<TaskId, BoxedFuture>,
+    ready_queue: ReadyQueue,
+    pending: Arc<Mutex<Vec<BoxedFuture>>>,
+    next_id: TaskId,
+}
+
+impl Executor {
+    pub(crate) fn new(wait_set: WaitSet, throttling: Option<Duration>) -> Self {
+        Self {
+            // Default to a 1ms quantum, matching the threadshare executor's default.
+            max_throttling: throttling.unwrap_or(Duration::from_millis(1)),
+            wait_set,
+            tasks: HashMap::new(),
+            ready_queue: ReadyQueue::default(),
+            pending: Arc::new(Mutex::new(Vec::new())),
+            next_id: 0,
+        }
+    }
+
+    /// Returns a [`Spawner`] that can submit futures to this executor.
+    pub fn spawner(&self) -> Spawner {
+        Spawner {
+            ready_queue: self.ready_queue.clone(),
+            pending: Arc::clone(&self.pending),
+        }
+    }
+
+    /// Moves any futures submitted through a [`Spawner`] into the task set.
+    fn drain_pending(&mut self) {
+        let pending: Vec<BoxedFuture> = std::mem::take(&mut *self.pending.lock().unwrap());
+        for future in pending {
+            self.insert(future);
+        }
+    }
+
+    fn insert(&mut self, future: BoxedFuture) {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.tasks.insert(id, future);
+        // Poll every freshly spawned task at least once on the next tick.
+        self.ready_queue.push(id);
+    }
+
+    /// Runs `future` to completion, driving the wait-set reactor until it resolves.
+    pub fn block_on<F>(&mut self, future: F) -> Result<(), RclrsError>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.insert(Box::pin(future));
+        let root = self.next_id - 1;
+
+        while self.tasks.contains_key(&root) {
+            self.tick()?;
+        }
+        Ok(())
+    }
+
+    /// Advances the executor by a single throttled tick.
+    fn tick(&mut self) -> Result<(), RclrsError> {
+        self.drain_pending();
+        let deadline = Instant::now() + self.max_throttling;
+
+        // Block in rcl_wait only for the remainder of the quantum, skipping the wait entirely when
+        // tasks are already queued so we don't add latency to pending wakeups. `wait` dispatches
+        // the callback of every ready entity in the wait set; a subscription created through
+        // [`Node::create_subscription_stream`][crate::Node::create_subscription_stream] pushes into
+        // its stream and wakes the awaiting `recv`, whose waker enqueues the task id for us.
+        if self.ready_queue.is_empty() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            self.wait_set.wait(Some(remaining))?;
+        }
+
+        // Collect and poll everything that became ready this tick in a single batch.
+        for id in self.ready_queue.drain() {
+            let Some(future) = self.tasks.get_mut(&id) else {
+                continue;
+            };
+            let waker = Waker::from(Arc::new(TaskWaker {
+                id,
+                ready_queue: self.ready_queue.clone(),
+            }));
+            let mut cx = TaskContext::from_waker(&waker);
+            if future.as_mut().poll(&mut cx).is_ready() {
+                self.tasks.remove(&id);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Handle for submitting futures to an [`Executor`].
+///
+/// A spawned future's waker pushes its task id onto the executor's ready-queue, which the wait loop
+/// drains each tick.
+#[derive(Clone)]
+pub struct Spawner {
+    ready_queue: ReadyQueue,
+    pending: Arc<Mutex<Vec<BoxedFuture>>>,
+}
+
+impl Spawner {
+    /// Queues a future to run on the executor.
+    ///
+    /// The future is picked up at the start of the next tick; pushing onto the ready-queue also
+    /// skips that tick's blocking wait so it is polled promptly.
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.pending.lock().unwrap().push(Box::pin(future));
+        // A sentinel id that task lookup ignores, forcing the wait loop to skip its blocking wait.
+        self.ready_queue.push(usize::MAX);
+    }
+}
@@ -0,0 +1,38 @@
+#![warn(missing_docs)]
+//! Rust client library for ROS 2.
+//!
+//! For getting started, see the [README][1].
+//!
+//! [1]: https://github.com/ros2-rust/ros2_rust/blob/main/README.md
+
+mod context;
+mod error;
+mod executor;
+mod node;
+mod node_options;
+mod node_untyped;
+mod parameter;
+mod publisher;
+mod publisher_untyped;
+mod qos;
+mod subscription;
+mod subscription_stream;
+mod subscription_untyped;
+mod type_support;
+mod wait_set;
+
+mod rcl_bindings;
+
+pub use context::*;
+pub use error::*;
+pub use executor::*;
+pub use node::*;
+pub use node_options::*;
+pub use parameter::*;
+pub use publisher::*;
+pub use publisher_untyped::*;
+pub use qos::*;
+pub use subscription::*;
+pub use subscription_stream::*;
+pub use subscription_untyped::*;
+pub use wait_set::*;
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use crate::error::RclrsError;
+use crate::parameter::{Parameter, ParameterOverrides, ParameterValue};
+use crate::Node;
+
+/// Options controlling how a node is created, including its parameter configuration.
+///
+/// Modeled on the standard ROS 2 node options: parameter overrides parsed from the context's
+/// command line are applied on top of each declared default, and the flags below control how
+/// undeclared and override-derived parameters are treated.
+#[derive(Clone, Debug)]
+pub struct NodeOptions {
+    /// Overrides applied to declared parameters, typically sourced from the context's
+    /// `--ros-args -p`/`--params-file` arguments.
+    pub parameter_overrides: Vec<Parameter>,
+    /// Whether the context's global arguments are considered in addition to node-local ones.
+    pub use_global_arguments: bool,
+    /// Whether getting or setting a parameter that was never declared is allowed.
+    pub allow_undeclared_parameters: bool,
+    /// Whether override entries without a matching declaration are declared automatically.
+    pub automatically_declare_parameters_from_overrides: bool,
+}
+
+impl Default for NodeOptions {
+    fn default() -> Self {
+        Self {
+            parameter_overrides: Vec::new(),
+            use_global_arguments: true,
+            allow_undeclared_parameters: false,
+            automatically_declare_parameters_from_overrides: false,
+        }
+    }
+}
+
+impl NodeOptions {
+    /// Creates node options with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the parameter overrides from a parsed [`ParameterOverrides`].
+    pub fn parameter_overrides(mut self, overrides: &ParameterOverrides) -> Self {
+        self.parameter_overrides = overrides.to_vec();
+        self
+    }
+
+    /// Sets whether global (context) arguments are used.
+    pub fn use_global_arguments(mut self, enable: bool) -> Self {
+        self.use_global_arguments = enable;
+        self
+    }
+
+    /// Sets whether undeclared parameters may be get/set.
+    pub fn allow_undeclared_parameters(mut self, enable: bool) -> Self {
+        self.allow_undeclared_parameters = enable;
+        self
+    }
+
+    /// Sets whether override entries are declared automatically.
+    pub fn automatically_declare_parameters_from_overrides(mut self, enable: bool) -> Self {
+        self.automatically_declare_parameters_from_overrides = enable;
+        self
+    }
+
+    /// Builds the parameter store a node holds, seeding it from the overrides.
+    pub(crate) fn build_parameters(&self) -> Parameters {
+        let overrides = self
+            .parameter_overrides
+            .iter()
+            .map(|p| (p.name.clone(), p.value.clone()))
+            .collect::<HashMap<_, _>>();
+        let mut values = HashMap::new();
+        if self.automatically_declare_parameters_from_overrides {
+            values.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        Parameters {
+            values,
+            overrides,
+            allow_undeclared: self.allow_undeclared_parameters,
+        }
+    }
+}
+
+/// `NodeBuilder` is a convenience alias for [`NodeOptions`], which doubles as the builder.
+pub type NodeBuilder = NodeOptions;
+
+/// Runtime parameter store owned by a [`Node`].
+#[derive(Clone, Debug, Default)]
+pub struct Parameters {
+    values: HashMap<String, ParameterValue>,
+    overrides: HashMap<String, ParameterValue>,
+    allow_undeclared: bool,
+}
+
+impl Node {
+    /// Declares a parameter with a default value, returning its effective value.
+    ///
+    /// If the context supplied an override for `name`, the override wins over `default`.
+    pub fn declare_parameter(&self, name: &str, default: ParameterValue) -> ParameterValue {
+        let params = &mut *self.parameters.lock().unwrap();
+        let value = params
+            .overrides
+            .get(name)
+            .cloned()
+            .unwrap_or(default);
+        params.values.insert(name.to_owned(), value.clone());
+        value
+    }
+
+    /// Returns the current value of a declared parameter.
+    ///
+    /// Returns [`RclrsError::ParameterNotDeclared`] if the parameter has no value, i.e. it was
+    /// never declared or set. This holds even when undeclared parameters are allowed.
+    pub fn get_parameter(&self, name: &str) -> Result<ParameterValue, RclrsError> {
+        let params = &*self.parameters.lock().unwrap();
+        params
+            .values
+            .get(name)
+            .cloned()
+            .ok_or_else(|| RclrsError::ParameterNotDeclared {
+                name: name.to_owned(),
+            })
+    }
+
+    /// Sets the value of a parameter.
+    ///
+    /// Setting a parameter that was never declared is only permitted when
+    /// [`allow_undeclared_parameters`][NodeOptions::allow_undeclared_parameters] was enabled.
+    pub fn set_parameter(&self, name: &str, value: ParameterValue) -> Result<(), RclrsError> {
+        let params = &mut *self.parameters.lock().unwrap();
+        if !params.allow_undeclared && !params.values.contains_key(name) {
+            return Err(RclrsError::ParameterNotDeclared {
+                name: name.to_owned(),
+            });
+        }
+        params.values.insert(name.to_owned(), value);
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::{Arc, Weak};
+
+use crate::error::RclrsError;
+use crate::qos::QoSProfile;
+use crate::subscription::SubscriptionBase;
+use crate::{Node, PublisherUntyped, SubscriptionStream, SubscriptionUntyped};
+
+impl Node {
+    /// Creates a [`PublisherUntyped`] keyed by a runtime type name.
+    ///
+    /// Unlike [`create_publisher`][Self::create_publisher], the message type is given as a string
+    /// such as `"std_msgs/msg/String"` and messages are published as already-serialized CDR byte
+    /// buffers. This is useful for generic bridges, recorders and relays.
+    pub fn create_publisher_untyped(
+        &self,
+        topic: &str,
+        type_name: &str,
+        qos: QoSProfile,
+    ) -> Result<PublisherUntyped, RclrsError> {
+        PublisherUntyped::new(self, topic, type_name, qos)
+    }
+
+    /// Creates a [`SubscriptionUntyped`] keyed by a runtime type name.
+    ///
+    /// The callback receives the raw CDR bytes of each incoming message. See
+    /// [`create_publisher_untyped`][Self::create_publisher_untyped] for the typed-vs-untyped
+    /// trade-off.
+    ///
+    /// Like [`create_subscription`][Self::create_subscription], the subscription is registered with
+    /// the node so that it is added to the wait set and its callback is executed by
+    /// [`spin`][crate::spin].
+    pub fn create_subscription_untyped<F>(
+        &self,
+        topic: &str,
+        type_name: &str,
+        qos: QoSProfile,
+        callback: F,
+    ) -> Result<Arc<SubscriptionUntyped>, RclrsError>
+    where
+        F: FnMut(&[u8]) + 'static + Send,
+    {
+        let subscription = Arc::new(SubscriptionUntyped::new(self, topic, type_name, qos, callback)?);
+        self.subscriptions_mtx
+            .lock()
+            .unwrap()
+            .push(Arc::downgrade(&subscription) as Weak<dyn SubscriptionBase>);
+        Ok(subscription)
+    }
+
+    /// Creates an untyped subscription delivered as an async [`SubscriptionStream`].
+    ///
+    /// Rather than a callback, this returns a stream whose `recv().await` resolves with the raw CDR
+    /// bytes of each incoming message. Internally it installs the stream's sink as the subscription
+    /// callback and registers the subscription with the node, so an [`Executor`][crate::Executor]
+    /// driving this node's wait set delivers messages to awaiting tasks. The stream owns the
+    /// subscription; dropping it unsubscribes.
+    pub fn create_subscription_stream(
+        &self,
+        topic: &str,
+        type_name: &str,
+        qos: QoSProfile,
+    ) -> Result<SubscriptionStream<Vec<u8>>, RclrsError> {
+        let stream = SubscriptionStream::new();
+        let mut sink = stream.sink();
+        let subscription = Arc::new(SubscriptionUntyped::new(
+            self,
+            topic,
+            type_name,
+            qos,
+            move |bytes: &[u8]| sink(bytes.to_vec()),
+        )?);
+        self.subscriptions_mtx
+            .lock()
+            .unwrap()
+            .push(Arc::downgrade(&subscription) as Weak<dyn SubscriptionBase>);
+        Ok(stream.with_subscription(subscription))
+    }
+}
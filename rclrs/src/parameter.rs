@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+
+/// The value of a [`Parameter`].
+///
+/// Mirrors the ROS 2 parameter type system; the variants map onto the `rcl_interfaces` parameter
+/// value fields.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParameterValue {
+    Bool(bool),
+    Integer(i64),
+    Double(f64),
+    String(String),
+    BoolArray(Vec<bool>),
+    IntegerArray(Vec<i64>),
+    DoubleArray(Vec<f64>),
+    StringArray(Vec<String>),
+}
+
+impl ParameterValue {
+    /// Parses a value from the textual form used on the command line (`name:=value`) and in YAML
+    /// params files.
+    ///
+    /// A `[a, b, c]` flow sequence is parsed into the narrowest homogeneous array variant; anything
+    /// else is parsed as a [scalar][Self::parse_scalar].
+    fn parse(text: &str) -> ParameterValue {
+        let trimmed = text.trim();
+        if let Some(inner) = trimmed
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            return Self::parse_array(inner);
+        }
+        Self::parse_scalar(trimmed)
+    }
+
+    /// Parses a scalar value from the textual form used on the command line (`name:=value`).
+    ///
+    /// The ROS 2 grammar is followed loosely: `true`/`false` become booleans, anything parseable as
+    /// an integer or float becomes that, and everything else is a string.
+    fn parse_scalar(text: &str) -> ParameterValue {
+        match text {
+            "true" => return ParameterValue::Bool(true),
+            "false" => return ParameterValue::Bool(false),
+            _ => {}
+        }
+        if let Ok(integer) = text.parse::<i64>() {
+            return ParameterValue::Integer(integer);
+        }
+        if let Ok(double) = text.parse::<f64>() {
+            return ParameterValue::Double(double);
+        }
+        ParameterValue::String(unquote(text).to_owned())
+    }
+
+    /// Parses the comma-separated interior of a `[...]` sequence into a homogeneous array.
+    ///
+    /// The element types are promoted to the narrowest variant that fits all of them, matching the
+    /// ROS 2 value model: all booleans stay a bool array, all integers an integer array, a mix of
+    /// integers and doubles becomes a double array, and anything else a string array.
+    fn parse_array(inner: &str) -> ParameterValue {
+        let inner = inner.trim();
+        if inner.is_empty() {
+            return ParameterValue::StringArray(Vec::new());
+        }
+        let elements: Vec<ParameterValue> =
+            inner.split(',').map(|e| Self::parse_scalar(e.trim())).collect();
+
+        if elements.iter().all(|v| matches!(v, ParameterValue::Bool(_))) {
+            return ParameterValue::BoolArray(
+                elements
+                    .iter()
+                    .map(|v| matches!(v, ParameterValue::Bool(true)))
+                    .collect(),
+            );
+        }
+        if elements
+            .iter()
+            .all(|v| matches!(v, ParameterValue::Integer(_)))
+        {
+            return ParameterValue::IntegerArray(
+                elements
+                    .iter()
+                    .map(|v| match v {
+                        ParameterValue::Integer(i) => *i,
+                        _ => unreachable!(),
+                    })
+                    .collect(),
+            );
+        }
+        if elements.iter().all(|v| {
+            matches!(v, ParameterValue::Integer(_) | ParameterValue::Double(_))
+        }) {
+            return ParameterValue::DoubleArray(
+                elements
+                    .iter()
+                    .map(|v| match v {
+                        ParameterValue::Integer(i) => *i as f64,
+                        ParameterValue::Double(d) => *d,
+                        _ => unreachable!(),
+                    })
+                    .collect(),
+            );
+        }
+        ParameterValue::StringArray(
+            inner
+                .split(',')
+                .map(|e| unquote(e.trim()).to_owned())
+                .collect(),
+        )
+    }
+}
+
+/// Strips a single pair of matching `"` or `'` quotes from a scalar token, if present.
+fn unquote(text: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = text.strip_prefix(quote).and_then(|rest| rest.strip_suffix(quote)) {
+            return inner;
+        }
+    }
+    text
+}
+
+/// A named parameter and its value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parameter {
+    /// Fully-qualified parameter name.
+    pub name: String,
+    /// The parameter's value.
+    pub value: ParameterValue,
+}
+
+/// Parameter overrides parsed out of a context's command line arguments.
+///
+/// These are the `--ros-args -p name:=value` flags and `--params-file <file>` entries, collected so
+/// that a node can override its declared defaults with any matching entry at creation time.
+#[derive(Clone, Debug, Default)]
+pub struct ParameterOverrides {
+    overrides: HashMap<String, ParameterValue>,
+}
+
+impl ParameterOverrides {
+    /// Parses overrides from an iterator of command line arguments.
+    ///
+    /// Only the `--ros-args` section is inspected; `-p name:=value` pairs and `--params-file`
+    /// entries within it are collected and everything else (e.g. name remaps) is ignored here.
+    pub fn from_args<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut overrides = HashMap::new();
+        let mut in_ros_args = false;
+        let mut args = args.into_iter().map(|s| s.as_ref().to_owned()).peekable();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--ros-args" => in_ros_args = true,
+                // `--` terminates the ROS-specific section.
+                "--" => in_ros_args = false,
+                "-p" | "--param" if in_ros_args => {
+                    if let Some((name, value)) = args.next().and_then(|pair| parse_assignment(&pair))
+                    {
+                        overrides.insert(name, value);
+                    }
+                }
+                "--params-file" if in_ros_args => {
+                    if let Some(path) = args.next() {
+                        load_params_file(&path, &mut overrides);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self { overrides }
+    }
+
+    /// Returns the override for `name`, if one was provided.
+    pub fn get(&self, name: &str) -> Option<&ParameterValue> {
+        self.overrides.get(name)
+    }
+
+    /// Returns every override as a list of [`Parameter`]s.
+    pub fn to_vec(&self) -> Vec<Parameter> {
+        self.overrides
+            .iter()
+            .map(|(name, value)| Parameter {
+                name: name.clone(),
+                value: value.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Parses a single `name:=value` assignment.
+fn parse_assignment(pair: &str) -> Option<(String, ParameterValue)> {
+    let (name, value) = pair.split_once(":=")?;
+    Some((name.to_owned(), ParameterValue::parse(value)))
+}
+
+/// Loads `name: value` entries from a YAML params file into `overrides`.
+///
+/// A minimal reader is used that understands the standard ROS 2 layout
+/// (`node:\n  ros__parameters:\n    p: v`, with `/**` matching any node). Entries are keyed by the
+/// parameter name *relative to* `ros__parameters` — so `p` and nested `group.q` — matching the bare
+/// names [`Node::declare_parameter`][crate::Node::declare_parameter] looks up; the node-name header
+/// is not part of the key. Nested mappings below `ros__parameters` are flattened with `.`.
+///
+/// Indentation is tracked with a stack keyed on each line's actual indent width, so the file is not
+/// assumed to use any particular step size.
+fn load_params_file(path: &str, overrides: &mut HashMap<String, ParameterValue>) {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return;
+    };
+    // Stack of `(indent_width, key)` for the mapping headers enclosing the current line.
+    let mut stack: Vec<(usize, String)> = Vec::new();
+    for line in contents.lines() {
+        let trimmed = line.trim_end();
+        if trimmed.trim().is_empty() || trimmed.trim_start().starts_with('#') {
+            continue;
+        }
+        let indent = trimmed.len() - trimmed.trim_start().len();
+        // Leave only the headers that strictly enclose this line (smaller indent).
+        while stack.last().is_some_and(|(i, _)| *i >= indent) {
+            stack.pop();
+        }
+        let Some((key, value)) = trimmed.trim().split_once(':') else {
+            continue;
+        };
+        let key = key.trim().trim_matches('"');
+        let value = value.trim();
+        if value.is_empty() {
+            // A mapping header such as `node_name:`, `/**:` or `ros__parameters:`.
+            stack.push((indent, key.to_owned()));
+        } else if let Some(name) = parameter_name_under_ros_parameters(&stack, key) {
+            overrides.insert(name, ParameterValue::parse(value));
+        }
+    }
+}
+
+/// Builds the node-relative parameter name for a leaf under a `ros__parameters` mapping.
+///
+/// Returns `None` for leaves that are not inside a `ros__parameters` section (e.g. stray top-level
+/// keys), so only genuine parameters are collected. The node-name header (including the `/**`
+/// wildcard) precedes `ros__parameters` and is therefore excluded from the key.
+fn parameter_name_under_ros_parameters(stack: &[(usize, String)], key: &str) -> Option<String> {
+    let ros_parameters = stack
+        .iter()
+        .rposition(|(_, segment)| segment == "ros__parameters")?;
+    let name = stack[ros_parameters + 1..]
+        .iter()
+        .map(|(_, segment)| segment.as_str())
+        .chain(std::iter::once(key))
+        .collect::<Vec<_>>()
+        .join(".");
+    Some(name)
+}
@@ -0,0 +1,122 @@
+use std::ffi::CString;
+use std::sync::{Arc, Mutex};
+
+use libloading::Library;
+
+use crate::error::{to_rclrs_result, RclrsError};
+use crate::qos::QoSProfile;
+use crate::rcl_bindings::*;
+use crate::type_support::message_type_support_handle;
+use crate::Node;
+
+/// A publisher keyed by a runtime type name rather than a compile-time [`Message`] type.
+///
+/// Unlike [`Publisher`][crate::Publisher], an untyped publisher accepts an already-serialized CDR
+/// byte buffer and forwards it with `rcl_publish_serialized_message`. This is intended for generic
+/// bridges, recorders and relays that forward messages without knowing the concrete Rust type at
+/// build time.
+pub struct PublisherUntyped {
+    rcl_publisher_mtx: Mutex<rcl_publisher_t>,
+    rcl_node_mtx: Arc<Mutex<rcl_node_t>>,
+    type_name: String,
+    // Keeps the dynamically loaded typesupport library alive for as long as the handle is in use.
+    #[allow(dead_code)]
+    type_support_library: Library,
+}
+
+// SAFETY: The functions accessing this type, including drop(), shouldn't care about the thread
+// they are running in. Therefore, this type can be safely sent to another thread.
+unsafe impl Send for PublisherUntyped {}
+
+impl Drop for PublisherUntyped {
+    fn drop(&mut self) {
+        unsafe {
+            // SAFETY: No preconditions for this function (besides the arguments being valid).
+            rcl_publisher_fini(
+                self.rcl_publisher_mtx.get_mut().unwrap(),
+                &mut *self.rcl_node_mtx.lock().unwrap(),
+            );
+        }
+    }
+}
+
+impl PublisherUntyped {
+    /// Creates a new untyped publisher.
+    ///
+    /// Called by [`Node::create_publisher_untyped`][crate::Node::create_publisher_untyped].
+    pub(crate) fn new(
+        node: &Node,
+        topic: &str,
+        type_name: &str,
+        qos: QoSProfile,
+    ) -> Result<Self, RclrsError>
+    where
+        Self: Sized,
+    {
+        let (type_support_library, type_support) = message_type_support_handle(type_name)?;
+        let topic_c_string = CString::new(topic).map_err(|err| RclrsError::StringContainsNul {
+            err,
+            s: topic.to_owned(),
+        })?;
+
+        let mut rcl_publisher = unsafe { rcl_get_zero_initialized_publisher() };
+        let rcl_node = &mut *node.rcl_node_mtx.lock().unwrap();
+        unsafe {
+            let mut publisher_options = rcl_publisher_get_default_options();
+            publisher_options.qos = qos.into();
+            // SAFETY: The type support handle is kept alive by `type_support_library`, the topic
+            // name is a valid C string, and the options are fully initialized.
+            rcl_publisher_init(
+                &mut rcl_publisher,
+                rcl_node,
+                type_support,
+                topic_c_string.as_ptr(),
+                &publisher_options,
+            )
+            .ok()?;
+        }
+
+        Ok(Self {
+            rcl_publisher_mtx: Mutex::new(rcl_publisher),
+            rcl_node_mtx: node.rcl_node_mtx.clone(),
+            type_name: type_name.to_owned(),
+            type_support_library,
+        })
+    }
+
+    /// The runtime type name this publisher was created with.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Publishes an already-serialized CDR message.
+    ///
+    /// The caller is responsible for producing a buffer matching this publisher's
+    /// [`type_name`][Self::type_name].
+    pub fn publish(&self, serialized: &[u8]) -> Result<(), RclrsError> {
+        let mut serialized_message = unsafe { rcutils_get_zero_initialized_uint8_array() };
+        // SAFETY: The serialized message is zero-initialized above; `rcl_publish_serialized_message`
+        // only reads from it.
+        unsafe {
+            let allocator = rcutils_get_default_allocator();
+            rcutils_uint8_array_init(&mut serialized_message, serialized.len(), &allocator)
+                .ok()?;
+            std::ptr::copy_nonoverlapping(
+                serialized.as_ptr(),
+                serialized_message.buffer,
+                serialized.len(),
+            );
+            serialized_message.buffer_length = serialized.len();
+
+            let rcl_publisher = &*self.rcl_publisher_mtx.lock().unwrap();
+            let ret = rcl_publish_serialized_message(
+                rcl_publisher,
+                &serialized_message,
+                std::ptr::null_mut(),
+            );
+            let result = to_rclrs_result(ret);
+            rcutils_uint8_array_fini(&mut serialized_message);
+            result
+        }
+    }
+}
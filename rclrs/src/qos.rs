@@ -0,0 +1,274 @@
+use std::time::Duration;
+
+use crate::rcl_bindings::*;
+
+/// The kinds of reliability that can be requested for a publisher or subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QoSReliabilityPolicy {
+    /// Defer to the underlying RMW implementation's default.
+    SystemDefault,
+    /// Guarantee delivery, retrying as needed.
+    Reliable,
+    /// Deliver on a best-effort basis without retries.
+    BestEffort,
+}
+
+/// Whether samples are kept for late-joining subscriptions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QoSDurabilityPolicy {
+    /// Defer to the underlying RMW implementation's default.
+    SystemDefault,
+    /// Keep and re-deliver samples to late-joiners.
+    TransientLocal,
+    /// Do not keep samples for late-joiners.
+    Volatile,
+}
+
+/// How many samples are stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QoSHistoryPolicy {
+    /// Defer to the underlying RMW implementation's default.
+    SystemDefault,
+    /// Keep only the last `depth` samples.
+    KeepLast {
+        /// The number of samples to keep.
+        depth: u32,
+    },
+    /// Keep all samples, subject to resource limits.
+    KeepAll,
+}
+
+/// Whether and how liveliness of publishers is asserted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QoSLivelinessPolicy {
+    /// Defer to the underlying RMW implementation's default.
+    SystemDefault,
+    /// The RMW layer asserts liveliness automatically.
+    Automatic,
+    /// The publishing node asserts liveliness for its publishers.
+    ManualByTopic,
+}
+
+/// A QoS duration such as a deadline, lifespan or liveliness lease.
+///
+/// The RMW layer distinguishes three cases that a plain [`Duration`] cannot, so they are modeled
+/// explicitly: deferring to the RMW default is not the same as an explicitly infinite duration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QoSDuration {
+    /// Defer to the underlying RMW implementation's default.
+    SystemDefault,
+    /// No bound: the duration never elapses.
+    Infinite,
+    /// A specific, finite duration.
+    Custom(Duration),
+}
+
+/// A quality-of-service profile controlling delivery of messages on a topic.
+///
+/// Build one with the named presets ([`sensor_data`][Self::sensor_data],
+/// [`services_default`][Self::services_default], [`parameters`][Self::parameters],
+/// [`system_default`][Self::system_default]) or by tweaking [`QOS_PROFILE_DEFAULT`] with the
+/// builder-style setters, then pass it anywhere a QoS profile is accepted such as
+/// [`Node::create_publisher`][crate::Node::create_publisher].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QoSProfile {
+    /// The history policy (and, for `KeepLast`, the queue depth).
+    pub history: QoSHistoryPolicy,
+    /// The reliability policy.
+    pub reliability: QoSReliabilityPolicy,
+    /// The durability policy.
+    pub durability: QoSDurabilityPolicy,
+    /// The expected maximum amount of time between subsequent messages.
+    pub deadline: QoSDuration,
+    /// The maximum amount of time a sample remains valid.
+    pub lifespan: QoSDuration,
+    /// The liveliness policy.
+    pub liveliness: QoSLivelinessPolicy,
+    /// The time within which a publisher must assert liveliness.
+    pub liveliness_lease_duration: QoSDuration,
+    /// Whether to omit the ROS-specific namespacing conventions when forming the entity's topic name.
+    pub avoid_ros_namespace_conventions: bool,
+}
+
+impl QoSProfile {
+    /// Profile for streaming sensor data: best-effort, volatile, keep the last 5 samples.
+    pub fn sensor_data() -> Self {
+        Self {
+            history: QoSHistoryPolicy::KeepLast { depth: 5 },
+            reliability: QoSReliabilityPolicy::BestEffort,
+            durability: QoSDurabilityPolicy::Volatile,
+            ..QOS_PROFILE_DEFAULT
+        }
+    }
+
+    /// Profile for service calls: reliable, keep the last 10 samples.
+    pub fn services_default() -> Self {
+        Self {
+            history: QoSHistoryPolicy::KeepLast { depth: 10 },
+            reliability: QoSReliabilityPolicy::Reliable,
+            durability: QoSDurabilityPolicy::Volatile,
+            ..QOS_PROFILE_DEFAULT
+        }
+    }
+
+    /// Profile for parameter transport: reliable with a large queue depth.
+    pub fn parameters() -> Self {
+        Self {
+            history: QoSHistoryPolicy::KeepLast { depth: 1000 },
+            reliability: QoSReliabilityPolicy::Reliable,
+            durability: QoSDurabilityPolicy::Volatile,
+            ..QOS_PROFILE_DEFAULT
+        }
+    }
+
+    /// Profile deferring every field to the underlying RMW implementation's defaults.
+    pub fn system_default() -> Self {
+        Self {
+            history: QoSHistoryPolicy::SystemDefault,
+            reliability: QoSReliabilityPolicy::SystemDefault,
+            durability: QoSDurabilityPolicy::SystemDefault,
+            deadline: QoSDuration::SystemDefault,
+            lifespan: QoSDuration::SystemDefault,
+            liveliness: QoSLivelinessPolicy::SystemDefault,
+            liveliness_lease_duration: QoSDuration::SystemDefault,
+            avoid_ros_namespace_conventions: false,
+        }
+    }
+
+    /// Sets the history policy to `KeepLast` with the given depth.
+    pub fn keep_last(mut self, depth: u32) -> Self {
+        self.history = QoSHistoryPolicy::KeepLast { depth };
+        self
+    }
+
+    /// Sets the history policy to `KeepAll`.
+    pub fn keep_all(mut self) -> Self {
+        self.history = QoSHistoryPolicy::KeepAll;
+        self
+    }
+
+    /// Sets the reliability policy.
+    pub fn reliability(mut self, reliability: QoSReliabilityPolicy) -> Self {
+        self.reliability = reliability;
+        self
+    }
+
+    /// Sets the durability policy.
+    pub fn durability(mut self, durability: QoSDurabilityPolicy) -> Self {
+        self.durability = durability;
+        self
+    }
+
+    /// Sets the deadline duration.
+    pub fn deadline(mut self, deadline: QoSDuration) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Sets the lifespan duration.
+    pub fn lifespan(mut self, lifespan: QoSDuration) -> Self {
+        self.lifespan = lifespan;
+        self
+    }
+
+    /// Sets the liveliness policy.
+    pub fn liveliness(mut self, liveliness: QoSLivelinessPolicy) -> Self {
+        self.liveliness = liveliness;
+        self
+    }
+}
+
+/// The default QoS profile: reliable, volatile, keep the last 10 samples.
+pub const QOS_PROFILE_DEFAULT: QoSProfile = QoSProfile {
+    history: QoSHistoryPolicy::KeepLast { depth: 10 },
+    reliability: QoSReliabilityPolicy::Reliable,
+    durability: QoSDurabilityPolicy::Volatile,
+    deadline: QoSDuration::SystemDefault,
+    lifespan: QoSDuration::SystemDefault,
+    liveliness: QoSLivelinessPolicy::SystemDefault,
+    liveliness_lease_duration: QoSDuration::SystemDefault,
+    avoid_ros_namespace_conventions: false,
+};
+
+/// The `rmw_time_t` sentinel a duration never elapsing, matching `RMW_DURATION_INFINITE`.
+const RMW_DURATION_INFINITE: rmw_time_t = rmw_time_t {
+    sec: 9223372036,
+    nsec: 854775807,
+};
+
+/// Converts a [`QoSDuration`] into its `rmw_time_t` representation.
+///
+/// The RMW layer overloads `rmw_time_t`: an all-zero time is the "default"/"unspecified" sentinel,
+/// while `RMW_DURATION_INFINITE` denotes a duration that never elapses. These are kept distinct so a
+/// finite [`QoSDuration::Custom`] is never confused with either.
+fn duration_to_rmw_time(duration: QoSDuration) -> rmw_time_t {
+    match duration {
+        // An all-zero time means "default"/"unspecified" to the RMW layer.
+        QoSDuration::SystemDefault => rmw_time_t { sec: 0, nsec: 0 },
+        QoSDuration::Infinite => RMW_DURATION_INFINITE,
+        QoSDuration::Custom(duration) => rmw_time_t {
+            sec: duration.as_secs(),
+            nsec: duration.subsec_nanos() as u64,
+        },
+    }
+}
+
+impl From<QoSProfile> for rmw_qos_profile_t {
+    fn from(qos: QoSProfile) -> Self {
+        let (history, depth) = match qos.history {
+            QoSHistoryPolicy::SystemDefault => {
+                (rmw_qos_history_policy_t::RMW_QOS_POLICY_HISTORY_SYSTEM_DEFAULT, 0)
+            }
+            QoSHistoryPolicy::KeepLast { depth } => {
+                (rmw_qos_history_policy_t::RMW_QOS_POLICY_HISTORY_KEEP_LAST, depth)
+            }
+            QoSHistoryPolicy::KeepAll => {
+                (rmw_qos_history_policy_t::RMW_QOS_POLICY_HISTORY_KEEP_ALL, 0)
+            }
+        };
+        let reliability = match qos.reliability {
+            QoSReliabilityPolicy::SystemDefault => {
+                rmw_qos_reliability_policy_t::RMW_QOS_POLICY_RELIABILITY_SYSTEM_DEFAULT
+            }
+            QoSReliabilityPolicy::Reliable => {
+                rmw_qos_reliability_policy_t::RMW_QOS_POLICY_RELIABILITY_RELIABLE
+            }
+            QoSReliabilityPolicy::BestEffort => {
+                rmw_qos_reliability_policy_t::RMW_QOS_POLICY_RELIABILITY_BEST_EFFORT
+            }
+        };
+        let durability = match qos.durability {
+            QoSDurabilityPolicy::SystemDefault => {
+                rmw_qos_durability_policy_t::RMW_QOS_POLICY_DURABILITY_SYSTEM_DEFAULT
+            }
+            QoSDurabilityPolicy::TransientLocal => {
+                rmw_qos_durability_policy_t::RMW_QOS_POLICY_DURABILITY_TRANSIENT_LOCAL
+            }
+            QoSDurabilityPolicy::Volatile => {
+                rmw_qos_durability_policy_t::RMW_QOS_POLICY_DURABILITY_VOLATILE
+            }
+        };
+        let liveliness = match qos.liveliness {
+            QoSLivelinessPolicy::SystemDefault => {
+                rmw_qos_liveliness_policy_t::RMW_QOS_POLICY_LIVELINESS_SYSTEM_DEFAULT
+            }
+            QoSLivelinessPolicy::Automatic => {
+                rmw_qos_liveliness_policy_t::RMW_QOS_POLICY_LIVELINESS_AUTOMATIC
+            }
+            QoSLivelinessPolicy::ManualByTopic => {
+                rmw_qos_liveliness_policy_t::RMW_QOS_POLICY_LIVELINESS_MANUAL_BY_TOPIC
+            }
+        };
+        rmw_qos_profile_t {
+            history,
+            depth: depth as usize,
+            reliability,
+            durability,
+            deadline: duration_to_rmw_time(qos.deadline),
+            lifespan: duration_to_rmw_time(qos.lifespan),
+            liveliness,
+            liveliness_lease_duration: duration_to_rmw_time(qos.liveliness_lease_duration),
+            avoid_ros_namespace_conventions: qos.avoid_ros_namespace_conventions,
+        }
+    }
+}
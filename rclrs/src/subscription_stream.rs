@@ -0,0 +1,115 @@
+use std::any::Any;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context as TaskContext, Poll, Waker};
+
+/// Async-friendly view over a subscription's incoming messages.
+///
+/// Instead of registering a blocking callback, a node can hold a `SubscriptionStream<T>` and
+/// `stream.recv().await` the next message from within a future driven by the
+/// [`Executor`][crate::Executor]. Messages delivered by the wait-set reactor are buffered here and
+/// the stored waker is notified so the awaiting task is re-polled.
+///
+/// Obtain one from [`Node::create_subscription_stream`][crate::Node::create_subscription_stream].
+pub struct SubscriptionStream<T> {
+    inner: Arc<Mutex<StreamInner<T>>>,
+    // Keeps the backing subscription registered with its node for as long as the stream is held, so
+    // dropping the stream tears the subscription down.
+    subscription: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+struct StreamInner<T> {
+    queue: VecDeque<T>,
+    waker: Option<Waker>,
+}
+
+impl<T> Clone for SubscriptionStream<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            subscription: self.subscription.clone(),
+        }
+    }
+}
+
+impl<T> Default for SubscriptionStream<T> {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(StreamInner {
+                queue: VecDeque::new(),
+                waker: None,
+            })),
+            subscription: None,
+        }
+    }
+}
+
+impl<T> SubscriptionStream<T> {
+    /// Creates an empty stream.
+    ///
+    /// [`Node::create_subscription_stream`][crate::Node::create_subscription_stream] constructs one
+    /// of these and installs [`sink`][Self::sink] as the callback of its untyped subscription, so
+    /// messages delivered by the wait-set reactor land here.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Buffers a message and wakes the awaiting task, if any.
+    ///
+    /// Called from the subscription's wait-set callback via [`sink`][Self::sink].
+    pub(crate) fn push(&self, message: T) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.queue.push_back(message);
+        if let Some(waker) = inner.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Returns a callback that feeds this stream, suitable as a subscription callback.
+    ///
+    /// The subscription registers this sink so that each message the wait set delivers is pushed
+    /// onto the stream and the awaiting `recv` future is woken.
+    pub(crate) fn sink(&self) -> impl FnMut(T) + Send + 'static
+    where
+        T: Send + 'static,
+    {
+        let stream = self.clone();
+        move |message| stream.push(message)
+    }
+
+    /// Ties the lifetime of the backing subscription to this stream.
+    ///
+    /// The node only holds a weak reference to the subscription, so the stream owns the strong one;
+    /// dropping the stream drops the subscription.
+    pub(crate) fn with_subscription(mut self, subscription: Arc<dyn Any + Send + Sync>) -> Self {
+        self.subscription = Some(subscription);
+        self
+    }
+
+    /// Resolves with the next received message.
+    pub fn recv(&self) -> Recv<'_, T> {
+        Recv { stream: self }
+    }
+}
+
+/// Future returned by [`SubscriptionStream::recv`].
+pub struct Recv<'a, T> {
+    stream: &'a SubscriptionStream<T>,
+}
+
+impl<T> Future for Recv<'_, T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let mut inner = self.stream.inner.lock().unwrap();
+        match inner.queue.pop_front() {
+            Some(message) => Poll::Ready(message),
+            None => {
+                inner.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::ffi::CString;
+use std::sync::{Arc, Mutex};
+
+use libloading::Library;
+
+use crate::error::{to_rclrs_result, RclrsError};
+use crate::qos::QoSProfile;
+use crate::rcl_bindings::*;
+use crate::subscription::{SubscriptionBase, SubscriptionHandle};
+use crate::type_support::message_type_support_handle;
+use crate::Node;
+
+/// A subscription keyed by a runtime type name rather than a compile-time [`Message`] type.
+///
+/// The callback receives the raw CDR bytes of each incoming message. This mirrors
+/// [`PublisherUntyped`][crate::PublisherUntyped] and is intended for generic bridges, recorders and
+/// relays that forward messages without knowing the concrete Rust type at build time.
+pub struct SubscriptionUntyped {
+    pub(crate) handle: Arc<SubscriptionHandle>,
+    type_name: String,
+    callback: Mutex<Box<dyn FnMut(&[u8]) + 'static + Send>>,
+    // Keeps the dynamically loaded typesupport library alive for as long as the handle is in use.
+    #[allow(dead_code)]
+    type_support_library: Library,
+}
+
+impl SubscriptionUntyped {
+    /// Creates a new untyped subscription.
+    ///
+    /// Called by [`Node::create_subscription_untyped`][crate::Node::create_subscription_untyped].
+    pub(crate) fn new<F>(
+        node: &Node,
+        topic: &str,
+        type_name: &str,
+        qos: QoSProfile,
+        callback: F,
+    ) -> Result<Self, RclrsError>
+    where
+        F: FnMut(&[u8]) + 'static + Send,
+        Self: Sized,
+    {
+        let (type_support_library, type_support) = message_type_support_handle(type_name)?;
+        let topic_c_string = CString::new(topic).map_err(|err| RclrsError::StringContainsNul {
+            err,
+            s: topic.to_owned(),
+        })?;
+
+        let mut rcl_subscription = unsafe { rcl_get_zero_initialized_subscription() };
+        {
+            let rcl_node = &mut *node.rcl_node_mtx.lock().unwrap();
+            unsafe {
+                let mut subscription_options = rcl_subscription_get_default_options();
+                subscription_options.qos = qos.into();
+                // SAFETY: The type support handle is kept alive by `type_support_library`, the topic
+                // name is a valid C string, and the options are fully initialized.
+                rcl_subscription_init(
+                    &mut rcl_subscription,
+                    rcl_node,
+                    type_support,
+                    topic_c_string.as_ptr(),
+                    &subscription_options,
+                )
+                .ok()?;
+            }
+        }
+
+        let handle = Arc::new(SubscriptionHandle {
+            rcl_subscription_mtx: Mutex::new(rcl_subscription),
+            node_handle: node.rcl_node_mtx.clone(),
+        });
+
+        Ok(Self {
+            handle,
+            type_name: type_name.to_owned(),
+            callback: Mutex::new(Box::new(callback)),
+            type_support_library,
+        })
+    }
+
+    /// The runtime type name this subscription was created with.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+}
+
+impl SubscriptionBase for SubscriptionUntyped {
+    fn handle(&self) -> &SubscriptionHandle {
+        &self.handle
+    }
+
+    /// Takes a single pending serialized message and delivers its raw bytes to the callback.
+    ///
+    /// Called by the wait set (via [`spin`][crate::spin] or an [`Executor`][crate::Executor]) when
+    /// this subscription is reported as ready.
+    fn execute(&self) -> Result<(), RclrsError> {
+        let mut serialized_message = unsafe { rcutils_get_zero_initialized_uint8_array() };
+        // SAFETY: The serialized message is zero-initialized; `rcl_take_serialized_message`
+        // populates it and we finalize it before returning.
+        unsafe {
+            let allocator = rcutils_get_default_allocator();
+            rcutils_uint8_array_init(&mut serialized_message, 0, &allocator).ok()?;
+
+            let rcl_subscription = &*self.handle.lock();
+            let ret = rcl_take_serialized_message(
+                rcl_subscription,
+                &mut serialized_message,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            );
+            let result = to_rclrs_result(ret).map(|()| {
+                let bytes = std::slice::from_raw_parts(
+                    serialized_message.buffer,
+                    serialized_message.buffer_length,
+                );
+                (*self.callback.lock().unwrap())(bytes);
+            });
+            rcutils_uint8_array_fini(&mut serialized_message);
+            result
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::ffi::CString;
+
+use libloading::{Library, Symbol};
+
+use crate::error::RclrsError;
+use crate::rcl_bindings::*;
+
+/// Resolves the message type support handle for a runtime type name such as
+/// `"std_msgs/msg/String"`.
+///
+/// The handle is looked up by loading the package's C typesupport library
+/// (`lib<package>__rosidl_typesupport_c.so`) and calling the per-message
+/// `rosidl_typesupport_c__get_message_type_support_handle__<package>__msg__<Type>` entry point,
+/// the same mechanism `rcl` uses internally for compile-time types.
+///
+/// The loaded [`Library`] is returned alongside the handle and must be kept alive for as long as
+/// the handle is used, so it is stored next to the publisher/subscription it backs.
+pub(crate) fn message_type_support_handle(
+    type_name: &str,
+) -> Result<(Library, *const rosidl_message_type_support_t), RclrsError> {
+    let (package, message) = split_type_name(type_name)?;
+
+    let library_name = format!("lib{package}__rosidl_typesupport_c.so");
+    // SAFETY: Loading a typesupport library has no preconditions beyond a valid path; any init
+    // routines it runs are the same ones `rcl` would trigger for a compile-time type.
+    let library = unsafe { Library::new(&library_name) }.map_err(|err| {
+        RclrsError::TypeSupportError {
+            type_name: type_name.to_owned(),
+            msg: format!("failed to load {library_name}: {err}"),
+        }
+    })?;
+
+    let symbol_name = CString::new(format!(
+        "rosidl_typesupport_c__get_message_type_support_handle__{package}__msg__{message}"
+    ))
+    .unwrap();
+    type GetHandleFn = unsafe extern "C" fn() -> *const rosidl_message_type_support_t;
+    // SAFETY: The symbol matches the signature generated by `rosidl` for every message type.
+    let handle = unsafe {
+        let get_handle: Symbol<GetHandleFn> =
+            library
+                .get(symbol_name.as_bytes_with_nul())
+                .map_err(|err| RclrsError::TypeSupportError {
+                    type_name: type_name.to_owned(),
+                    msg: format!("missing type support symbol: {err}"),
+                })?;
+        get_handle()
+    };
+
+    Ok((library, handle))
+}
+
+/// Splits a `"package/msg/Type"` type name into its package and message components.
+fn split_type_name(type_name: &str) -> Result<(&str, &str), RclrsError> {
+    match type_name.split('/').collect::<Vec<_>>()[..] {
+        [package, "msg", message] => Ok((package, message)),
+        _ => Err(RclrsError::TypeSupportError {
+            type_name: type_name.to_owned(),
+            msg: "expected a type name of the form \"package/msg/Type\"".to_owned(),
+        }),
+    }
+}